@@ -5,6 +5,7 @@ use collections::btree_map::BTreeMap;
 
 use core::str::FromStr;
 use core::fmt::{Debug, Formatter, Error};
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 use super::termlist::parse_term_list;
 use super::namedobj::{ RegionSpace, FieldFlags };
@@ -16,6 +17,7 @@ pub enum FieldSelector {
     Region(String),
     Bank {
         region: String,
+        bank_register: String,
         bank_selector: Box<AmlValue>
     },
     Index {
@@ -24,6 +26,16 @@ pub enum FieldSelector {
     }
 }
 
+/// How a partial (sub-access-width) write fills in the bits of the access
+/// unit that fall outside the field being written, per the `FieldFlags`
+/// UpdateRule.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum FieldUpdateRule {
+    Preserve,
+    WriteAsOnes,
+    WriteAsZeros
+}
+
 #[derive(Clone)]
 pub enum ObjectReference {
     ArgObj(u8),
@@ -55,6 +67,52 @@ impl Clone for Accessor {
     }
 }
 
+/// A per-`RegionSpace` handler for `OperationRegion` accesses. A kernel
+/// implements this for every address space its DSDT/SSDTs actually touch
+/// (PCI config space, the Embedded Controller, SMBus, ...) and registers
+/// it with `HandlerRegistry`, rather than the interpreter assuming flat
+/// `SystemMemory`-style access everywhere.
+pub trait RegionHandler {
+    fn read(&self, offset: usize, width: u8) -> u64;
+    fn write(&mut self, offset: usize, width: u8, value: u64);
+}
+
+/// A registry of `RegionHandler`s keyed by `RegionSpace`, consulted by
+/// `FieldUnit` evaluation instead of the fixed `Accessor` function
+/// pointers.
+pub struct HandlerRegistry {
+    handlers: Vec<(RegionSpace, Box<RegionHandler>)>
+}
+
+impl HandlerRegistry {
+    pub fn new() -> HandlerRegistry {
+        HandlerRegistry {
+            handlers: Vec::new()
+        }
+    }
+
+    /// Register `handler` for `space`, replacing any handler already
+    /// registered for that space.
+    pub fn register(&mut self, space: RegionSpace, handler: Box<RegionHandler>) {
+        for entry in self.handlers.iter_mut() {
+            if entry.0 == space {
+                entry.1 = handler;
+                return;
+            }
+        }
+
+        self.handlers.push((space, handler));
+    }
+
+    pub fn handler(&self, space: &RegionSpace) -> Option<&Box<RegionHandler>> {
+        self.handlers.iter().find(|entry| &entry.0 == space).map(|entry| &entry.1)
+    }
+
+    pub fn handler_mut(&mut self, space: &RegionSpace) -> Option<&mut Box<RegionHandler>> {
+        self.handlers.iter_mut().find(|entry| &entry.0 == space).map(|entry| &mut entry.1)
+    }
+}
+
 #[derive(Clone)]
 pub enum AmlValue {
     None,
@@ -108,6 +166,94 @@ impl Debug for AmlValue {
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> { Ok(()) }
 }
 
+/// Width, in bytes, of the Integer -> Buffer implicit conversion: 4 for a
+/// rev-1 DSDT, 8 for rev >= 2. Defaults to 8 (the common case) until a
+/// kernel calls `set_integer_width_bytes` after parsing the DSDT header.
+static INTEGER_WIDTH_BYTES: AtomicUsize = AtomicUsize::new(8);
+
+/// Sets the width used by `Conversion::integer_to_buffer`, per the DSDT's
+/// declared revision (`RevisionOverride` / table header `Revision`
+/// field). Should be called once, before any AML that relies on implicit
+/// Integer -> Buffer conversion is evaluated.
+pub fn set_integer_width_bytes(width: usize) {
+    INTEGER_WIDTH_BYTES.store(width, Ordering::Relaxed);
+}
+
+/// Implicit operand conversions between the three core ACPI data types, as
+/// used by term-list evaluation when an operator's operand isn't already
+/// the type it expects. Exact-type accessors (`get_as_*_strict`) bypass
+/// this table entirely.
+struct Conversion;
+
+impl Conversion {
+    fn integer_to_buffer(i: u64) -> Vec<u8> {
+        let width = INTEGER_WIDTH_BYTES.load(Ordering::Relaxed);
+        let mut buf = Vec::with_capacity(width);
+        let mut v = i;
+        for _ in 0..width {
+            buf.push((v & 0xFF) as u8);
+            v >>= 8;
+        }
+        buf
+    }
+
+    fn integer_to_string(i: u64) -> String {
+        if i == 0 {
+            return String::from("0");
+        }
+
+        let mut digits = Vec::new();
+        let mut v = i;
+        while v > 0 {
+            digits.push(b'0' + (v % 10) as u8);
+            v /= 10;
+        }
+
+        let mut s = String::with_capacity(digits.len());
+        for &d in digits.iter().rev() {
+            s.push(d as char);
+        }
+        s
+    }
+
+    fn buffer_to_integer(b: &[u8]) -> u64 {
+        let mut v: u64 = 0;
+        for (i, &byte) in b.iter().take(8).enumerate() {
+            v |= (byte as u64) << (i * 8);
+        }
+        v
+    }
+
+    fn buffer_to_string(b: &[u8]) -> String {
+        let mut s = String::with_capacity(b.len());
+        for &byte in b.iter() {
+            if byte == 0 {
+                break;
+            }
+            s.push(byte as char);
+        }
+        s
+    }
+
+    fn string_to_integer(s: &str) -> u64 {
+        let s = s.trim();
+
+        if s.starts_with("0x") || s.starts_with("0X") {
+            let digits: String = s[2..].chars().take_while(|c| c.is_digit(16)).collect();
+            return u64::from_str_radix(&digits, 16).unwrap_or(0);
+        }
+
+        let digits: String = s.chars().take_while(|c| c.is_digit(10)).collect();
+        u64::from_str_radix(&digits, 10).unwrap_or(0)
+    }
+
+    fn string_to_buffer(s: &str) -> Vec<u8> {
+        let mut buf: Vec<u8> = s.bytes().collect();
+        buf.push(0);
+        buf
+    }
+}
+
 impl AmlValue {
     pub fn get_as_event(&self) -> Result<u64, AmlError> {
         match *self {
@@ -116,20 +262,48 @@ impl AmlValue {
         }
     }
     
+    /// Exact-match string accessor - returns an error on anything but
+    /// `AmlValue::String`. Most callers want the implicit-conversion
+    /// version, `get_as_string`, instead.
+    pub fn get_as_string_strict(&self) -> Result<String, AmlError> {
+        match *self {
+            AmlValue::String(ref s) => Ok(s.clone()),
+            _ => Err(AmlError::AmlValueError)
+        }
+    }
+
+    /// String accessor with the ACPI implicit Integer/Buffer -> String
+    /// conversions applied when `self` isn't already a `String`.
     pub fn get_as_string(&self) -> Result<String, AmlError> {
         match *self {
             AmlValue::String(ref s) => Ok(s.clone()),
+            AmlValue::Integer(i) | AmlValue::IntegerConstant(i) => Ok(Conversion::integer_to_string(i)),
+            AmlValue::Buffer(ref b) => Ok(Conversion::buffer_to_string(b)),
+            _ => Err(AmlError::AmlValueError)
+        }
+    }
+
+    /// Exact-match buffer accessor - returns an error on anything but
+    /// `AmlValue::Buffer`. Most callers want the implicit-conversion
+    /// version, `get_as_buffer`, instead.
+    pub fn get_as_buffer_strict(&self) -> Result<Vec<u8>, AmlError> {
+        match *self {
+            AmlValue::Buffer(ref b) => Ok(b.clone()),
             _ => Err(AmlError::AmlValueError)
         }
     }
 
+    /// Buffer accessor with the ACPI implicit Integer/String -> Buffer
+    /// conversions applied when `self` isn't already a `Buffer`.
     pub fn get_as_buffer(&self) -> Result<Vec<u8>, AmlError> {
         match *self {
             AmlValue::Buffer(ref b) => Ok(b.clone()),
+            AmlValue::Integer(i) | AmlValue::IntegerConstant(i) => Ok(Conversion::integer_to_buffer(i)),
+            AmlValue::String(ref s) => Ok(Conversion::string_to_buffer(s)),
             _ => Err(AmlError::AmlValueError)
         }
     }
-    
+
     pub fn get_as_package(&self) -> Result<Vec<AmlValue>, AmlError> {
         match *self {
             AmlValue::Package(ref p) => Ok(p.clone()),
@@ -137,9 +311,23 @@ impl AmlValue {
         }
     }
 
+    /// Exact-match integer accessor - returns an error on anything but
+    /// `AmlValue::Integer`/`AmlValue::IntegerConstant`. Most callers want
+    /// the implicit-conversion version, `get_as_integer`, instead.
+    pub fn get_as_integer_strict(&self) -> Result<u64, AmlError> {
+        match *self {
+            AmlValue::Integer(ref i) | AmlValue::IntegerConstant(ref i) => Ok(i.clone()),
+            _ => Err(AmlError::AmlValueError)
+        }
+    }
+
+    /// Integer accessor with the ACPI implicit Buffer/String -> Integer
+    /// conversions applied when `self` isn't already an integer.
     pub fn get_as_integer(&self) -> Result<u64, AmlError> {
         match *self {
-            AmlValue::IntegerConstant(ref i) => Ok(i.clone()),
+            AmlValue::Integer(ref i) | AmlValue::IntegerConstant(ref i) => Ok(i.clone()),
+            AmlValue::Buffer(ref b) => Ok(Conversion::buffer_to_integer(b)),
+            AmlValue::String(ref s) => Ok(Conversion::string_to_integer(s)),
             _ => Err(AmlError::AmlValueError)
         }
     }
@@ -150,6 +338,13 @@ impl AmlValue {
             _ => Err(AmlError::AmlValueError)
         }
     }
+
+    pub fn get_as_mutex(&self) -> Result<(u8, Option<u64>), AmlError> {
+        match *self {
+            AmlValue::Mutex(ref m) => Ok(m.clone()),
+            _ => Err(AmlError::AmlValueError)
+        }
+    }
 }
 
 impl Method {
@@ -167,6 +362,151 @@ impl Method {
     }
 }
 
+/// A single 4-character ACPI NameSeg, padded with trailing `_` as the spec requires.
+#[derive(Clone, PartialEq, Eq)]
+pub struct NameSeg(pub [u8; 4]);
+
+impl NameSeg {
+    pub fn from_str(seg: &str) -> NameSeg {
+        let mut bytes = [b'_'; 4];
+        for (i, b) in seg.bytes().take(4).enumerate() {
+            bytes[i] = b;
+        }
+        NameSeg(bytes)
+    }
+
+    pub fn as_str(&self) -> String {
+        let mut s = String::new();
+        for &b in self.0.iter() {
+            s.push(b as char);
+        }
+        s
+    }
+}
+
+/// A parsed ACPI namespace path: an optional root anchor, a number of leading
+/// `^` (parent-scope) prefixes, and the remaining NameSegs.
+///
+/// This mirrors the structured name type used by full AML interpreters (e.g.
+/// the `aml` crate's `AmlName`), adapted to the dot-joined `String` form this
+/// module already uses to represent namespace paths.
+#[derive(Clone)]
+pub struct AmlName {
+    pub root: bool,
+    pub prefix: u8,
+    pub path: Vec<NameSeg>
+}
+
+impl AmlName {
+    /// The root of the namespace, `\`.
+    pub fn root() -> AmlName {
+        AmlName {
+            root: true,
+            prefix: 0,
+            path: Vec::new()
+        }
+    }
+
+    pub fn from_str(name: &str) -> Result<AmlName, AmlError> {
+        let mut name = name;
+        let root = if name.starts_with("\\") {
+            name = &name[1..];
+            true
+        } else {
+            false
+        };
+
+        let mut prefix = 0;
+        while name.starts_with("^") {
+            name = &name[1..];
+            prefix += 1;
+        }
+
+        let path = if name.len() == 0 {
+            Vec::new()
+        } else {
+            name.split('.').map(NameSeg::from_str).collect()
+        };
+
+        Ok(AmlName {
+            root: root,
+            prefix: prefix,
+            path: path
+        })
+    }
+
+    pub fn as_string(&self) -> String {
+        let mut s = String::new();
+
+        if self.root {
+            s.push('\\');
+        }
+
+        for _ in 0..self.prefix {
+            s.push('^');
+        }
+
+        for (i, seg) in self.path.iter().enumerate() {
+            if i > 0 {
+                s.push('.');
+            }
+            s += &seg.as_str();
+        }
+
+        s
+    }
+
+    /// Resolve this name against `scope`, following the ACPI rules for the
+    /// `^` parent prefix: an absolute name (`root == true`) is returned as
+    /// is, otherwise one trailing segment is stripped off `scope` for every
+    /// leading `^`, erroring if that would walk past the root, and the
+    /// remaining segments of `self` are appended.
+    pub fn resolve(&self, scope: &AmlName) -> Result<AmlName, AmlError> {
+        if self.root {
+            return Ok(self.clone());
+        }
+
+        if (self.prefix as usize) > scope.path.len() {
+            return Err(AmlError::AmlValueError);
+        }
+
+        let base_len = scope.path.len() - self.prefix as usize;
+        let mut path: Vec<NameSeg> = scope.path[0..base_len].to_vec();
+        path.extend(self.path.iter().cloned());
+
+        Ok(AmlName {
+            root: true,
+            prefix: 0,
+            path: path
+        })
+    }
+
+    /// The chain of scopes from `scope` up to (and including) the root,
+    /// used to implement the ACPI "upward search" rule: a single-segment
+    /// relative name that isn't bound in the current scope is looked up at
+    /// each enclosing scope in turn.
+    pub fn search_scopes(scope: &AmlName) -> Vec<AmlName> {
+        let mut scopes = Vec::new();
+        let mut path = scope.path.clone();
+
+        loop {
+            scopes.push(AmlName {
+                root: true,
+                prefix: 0,
+                path: path.clone()
+            });
+
+            if path.len() == 0 {
+                break;
+            }
+
+            path.pop();
+        }
+
+        scopes
+    }
+}
+
 pub fn get_namespace_string(current: String, modifier_v: AmlValue) -> String {
     // TODO: Type error if modifier not string
     let modifier = if let Ok(s) = modifier_v.get_as_string() {
@@ -174,7 +514,7 @@ pub fn get_namespace_string(current: String, modifier_v: AmlValue) -> String {
     } else {
         return current;
     };
-    
+
     if current.len() == 0 {
         return modifier;
     }
@@ -182,20 +522,782 @@ pub fn get_namespace_string(current: String, modifier_v: AmlValue) -> String {
     if modifier.len() == 0 {
         return current;
     }
-    
+
     if modifier.starts_with("\\") {
         return modifier;
     }
 
-    if modifier.starts_with("^") {
-        // TODO
+    let current_name = match AmlName::from_str(&current) {
+        Ok(n) => n,
+        Err(_) => return current
+    };
+
+    let modifier_name = match AmlName::from_str(&modifier) {
+        Ok(n) => n,
+        Err(_) => return current
+    };
+
+    match modifier_name.resolve(&current_name) {
+        Ok(resolved) => resolved.as_string(),
+        Err(_) => current
+    }
+}
+
+/// Public entry point for a kernel to look up ACPI objects and invoke
+/// control methods by namespace path, without needing to know about
+/// `Method::execute`, `AmlName` resolution, or term-list evaluation.
+pub struct AmlContext {
+    namespace: BTreeMap<String, AmlValue>,
+    region_handlers: HandlerRegistry,
+    mutexes: BTreeMap<String, MutexState>,
+    events: BTreeMap<String, u64>,
+    thread_sync_levels: BTreeMap<u64, Vec<u8>>
+}
+
+/// Ownership state for a `Mutex` object, tracked outside the (`Clone`,
+/// by-value) `AmlValue` itself, the same way `HandlerRegistry` tracks
+/// `OperationRegion` handlers outside the value.
+struct MutexState {
+    owner: Option<u64>,
+    recursion: u32
+}
+
+/// Result of an `Acquire` or `Wait` operation - ACPI represents both as a
+/// Boolean (`true` meaning the timeout elapsed before success), but a
+/// named result reads better at call sites.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum WaitResult {
+    Acquired,
+    TimedOut
+}
+
+/// Number of attempts, the first included, a contended `Acquire`/`Wait`
+/// makes within the call before giving up, for any timeout other than
+/// `Some(0)` ("try once").
+/// This interpreter has no scheduler to block a thread on, so this is a
+/// bounded spin rather than an actual wait for `timeout` to elapse.
+const MUTEX_ACQUIRE_RETRIES: u32 = 1000;
+
+impl AmlContext {
+    pub fn new() -> AmlContext {
+        AmlContext {
+            namespace: BTreeMap::new(),
+            region_handlers: HandlerRegistry::new(),
+            mutexes: BTreeMap::new(),
+            events: BTreeMap::new(),
+            thread_sync_levels: BTreeMap::new()
+        }
     }
 
-    let mut namespace = current.clone();
+    /// Register `handler` to service `OperationRegion`/`FieldUnit` accesses
+    /// to `space`.
+    pub fn register_region_handler(&mut self, space: RegionSpace, handler: Box<RegionHandler>) {
+        self.region_handlers.register(space, handler);
+    }
+
+    fn read_region(&self, region_value: &AmlValue, offset: usize, width: u8) -> Result<u64, AmlError> {
+        match *region_value {
+            AmlValue::OperationRegion { ref region, ref accessor, .. } => {
+                match self.region_handlers.handler(region) {
+                    Some(handler) => Ok(handler.read(offset, width)),
+                    None => Ok((accessor.read)(offset))
+                }
+            },
+            _ => Err(AmlError::AmlValueError)
+        }
+    }
 
-    if !namespace.ends_with("\\") {
-        namespace.push('.');
+    fn write_region(&mut self, region_value: &AmlValue, offset: usize, width: u8, value: u64) -> Result<(), AmlError> {
+        match *region_value {
+            AmlValue::OperationRegion { ref region, ref accessor, .. } => {
+                match self.region_handlers.handler_mut(region) {
+                    Some(handler) => {
+                        handler.write(offset, width, value);
+                        Ok(())
+                    },
+                    None => {
+                        (accessor.write)(offset, value);
+                        Ok(())
+                    }
+                }
+            },
+            _ => Err(AmlError::AmlValueError)
+        }
+    }
+
+    /// Bind `name` (resolved against `scope`) to `value` in the namespace.
+    pub fn define(&mut self, scope: &AmlName, name: &AmlName, value: AmlValue) -> Result<(), AmlError> {
+        let resolved = match name.resolve(scope) {
+            Ok(r) => r,
+            Err(e) => return Err(e)
+        };
+
+        self.namespace.insert(resolved.as_string(), value);
+        Ok(())
+    }
+
+    fn lookup_from(&self, name: &AmlName, scope: &AmlName) -> Option<AmlValue> {
+        let resolved = match name.resolve(scope) {
+            Ok(r) => r,
+            Err(_) => return None
+        };
+
+        if let Some(v) = self.namespace.get(&resolved.as_string()) {
+            return Some(v.clone());
+        }
+
+        // ACPI upward search: a single-segment relative name that isn't
+        // bound at the computed scope is looked up at each enclosing
+        // scope, walking toward the root.
+        if !name.root && name.prefix == 0 && name.path.len() == 1 {
+            for candidate in AmlName::search_scopes(scope) {
+                let mut path = candidate.path.clone();
+                path.extend(name.path.iter().cloned());
+                let probe = AmlName { root: true, prefix: 0, path: path };
+
+                if let Some(v) = self.namespace.get(&probe.as_string()) {
+                    return Some(v.clone());
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Look up an ACPI object by path, resolved against the root of the
+    /// namespace. Returns the object directly - for a `Method`, this is
+    /// the `AmlValue::Method` itself, not the result of running it. Use
+    /// `invoke_method` to run a control method.
+    pub fn lookup(&self, name: &AmlName) -> Option<AmlValue> {
+        self.lookup_from(name, &AmlName::root())
+    }
+
+    /// Resolve `name` to a `Method` and run it with `parameters`, erroring
+    /// if the name doesn't resolve to a method or the parameter count
+    /// doesn't match the method's declared `arg_count`.
+    pub fn invoke_method(&self, name: &AmlName, parameters: Vec<AmlValue>) -> Result<AmlValue, AmlError> {
+        let value = match self.lookup(name) {
+            Some(v) => v,
+            None => return Err(AmlError::AmlValueError)
+        };
+
+        let method = match value.get_as_method() {
+            Ok(m) => m,
+            Err(e) => return Err(e)
+        };
+
+        if method.arg_count as usize != parameters.len() {
+            return Err(AmlError::AmlValueError);
+        }
+
+        Ok(method.execute(name.as_string(), parameters))
+    }
+
+    /// Resolve a field selector's named component (an `OperationRegion` or
+    /// another `FieldUnit`) - accepting both already-absolute paths and
+    /// paths relative to the root, since `FieldSelector` strings are
+    /// stored as parsed at definition time.
+    fn named(&self, name: &str) -> Result<AmlValue, AmlError> {
+        if let Some(v) = self.namespace.get(name) {
+            return Ok(v.clone());
+        }
+
+        let parsed = match AmlName::from_str(name) {
+            Ok(n) => n,
+            Err(e) => return Err(e)
+        };
+
+        self.lookup(&parsed).ok_or(AmlError::AmlValueError)
+    }
+
+    fn write_named_integer(&mut self, name: &str, value: u64) -> Result<(), AmlError> {
+        let field = try!(self.named(name));
+        self.write_field(&field, &AmlValue::Integer(value))
+    }
+
+    /// Evaluate a `FieldUnit` access, returning the `offset..offset+length`
+    /// bit slice. Fields of 64 bits or less are returned as an
+    /// `AmlValue::Integer`; wider fields don't fit the operand-width
+    /// integer type and are returned as an `AmlValue::Buffer` instead, per
+    /// the ACPI operand-width rules. `Bank` and `Index` selectors drive
+    /// their control field(s) first, as ACPI requires.
+    pub fn read_field(&mut self, field: &AmlValue) -> Result<AmlValue, AmlError> {
+        match *field {
+            AmlValue::FieldUnit { ref selector, ref flags, offset, length, .. } => {
+                if length <= 64 {
+                    self.read_field_bits(selector, flags, offset, length).map(AmlValue::Integer)
+                } else {
+                    self.read_field_buffer(selector, flags, offset, length).map(AmlValue::Buffer)
+                }
+            },
+            _ => Err(AmlError::AmlValueError)
+        }
+    }
+
+    /// Read a field wider than the 64-bit integer width by gathering it in
+    /// 64-bit (or smaller, for the final remainder) chunks and packing
+    /// them little-endian into a byte buffer.
+    fn read_field_buffer(&mut self, selector: &FieldSelector, flags: &FieldFlags, offset: usize, length: usize) -> Result<Vec<u8>, AmlError> {
+        let mut buf = Vec::with_capacity((length + 7) / 8);
+
+        for (chunk_offset, chunk_bits) in chunk_ranges(offset, length) {
+            let chunk = try!(self.read_field_bits(selector, flags, chunk_offset, chunk_bits));
+
+            for i in 0..(chunk_bits + 7) / 8 {
+                buf.push(((chunk >> (i * 8)) & 0xFF) as u8);
+            }
+        }
+
+        Ok(buf)
+    }
+
+    /// Evaluate a `FieldUnit` write of `value` into the `offset..offset+length`
+    /// bit slice. Fields of 64 bits or less take `value` coerced to an
+    /// integer; wider fields (128-bit UUID/EISAID-style fields are common
+    /// in real DSDTs) take it coerced to a `Buffer` and write it in
+    /// `read_field_buffer`'s chunks instead, since a single `u64` can't
+    /// hold - or be shifted across - more than 64 bits of it. Partial
+    /// access-width writes honor the field's `FieldUpdateRule`.
+    pub fn write_field(&mut self, field: &AmlValue, value: &AmlValue) -> Result<(), AmlError> {
+        match *field {
+            AmlValue::FieldUnit { ref selector, ref flags, offset, length, .. } => {
+                if length <= 64 {
+                    let v = try!(value.get_as_integer());
+                    self.write_field_bits(selector, flags, offset, length, v)
+                } else {
+                    let buf = try!(value.get_as_buffer());
+                    self.write_field_buffer(selector, flags, offset, length, &buf)
+                }
+            },
+            _ => Err(AmlError::AmlValueError)
+        }
+    }
+
+    /// Write a field wider than the 64-bit integer width by splitting
+    /// `buf` into the same ≤64-bit, little-endian chunks `read_field_buffer`
+    /// gathers a wide field into, and writing each chunk through
+    /// `write_field_bits` in turn.
+    fn write_field_buffer(&mut self, selector: &FieldSelector, flags: &FieldFlags, offset: usize, length: usize, buf: &[u8]) -> Result<(), AmlError> {
+        let mut byte_pos = 0;
+
+        for (chunk_offset, chunk_bits) in chunk_ranges(offset, length) {
+            let chunk_bytes = (chunk_bits + 7) / 8;
+
+            let mut chunk: u64 = 0;
+            for i in 0..chunk_bytes {
+                let byte = *buf.get(byte_pos + i).unwrap_or(&0);
+                chunk |= (byte as u64) << (i * 8);
+            }
+
+            try!(self.write_field_bits(selector, flags, chunk_offset, chunk_bits, chunk));
+            byte_pos += chunk_bytes;
+        }
+
+        Ok(())
+    }
+
+    fn read_field_bits(&mut self, selector: &FieldSelector, flags: &FieldFlags, offset: usize, length: usize) -> Result<u64, AmlError> {
+        match *selector {
+            FieldSelector::Region(ref name) => {
+                let region = try!(self.named(name));
+                self.read_region_bits(&region, flags, offset, length)
+            },
+            FieldSelector::Bank { ref region, ref bank_register, ref bank_selector } => {
+                let bank_value = try!(bank_selector.get_as_integer());
+                try!(self.write_named_integer(bank_register, bank_value));
+                let region = try!(self.named(region));
+                self.read_region_bits(&region, flags, offset, length)
+            },
+            FieldSelector::Index { ref index_selector, ref data_selector } =>
+                self.read_indexed_bits(index_selector, data_selector, flags, offset, length)
+        }
+    }
+
+    fn write_field_bits(&mut self, selector: &FieldSelector, flags: &FieldFlags, offset: usize, length: usize, value: u64) -> Result<(), AmlError> {
+        match *selector {
+            FieldSelector::Region(ref name) => {
+                let region = try!(self.named(name));
+                self.write_region_bits(&region, flags, offset, length, value)
+            },
+            FieldSelector::Bank { ref region, ref bank_register, ref bank_selector } => {
+                let bank_value = try!(bank_selector.get_as_integer());
+                try!(self.write_named_integer(bank_register, bank_value));
+                let region = try!(self.named(region));
+                self.write_region_bits(&region, flags, offset, length, value)
+            },
+            FieldSelector::Index { ref index_selector, ref data_selector } =>
+                self.write_indexed_bits(index_selector, data_selector, flags, offset, length, value)
+        }
+    }
+
+    /// A single region access is split into `access_width`-sized, aligned
+    /// units covering `offset..offset+length`, which are recombined
+    /// little-endian before the requested bit slice is masked out.
+    fn read_region_bits(&self, region: &AmlValue, flags: &FieldFlags, offset: usize, length: usize) -> Result<u64, AmlError> {
+        if length == 0 {
+            return Err(AmlError::AmlValueError);
+        }
+
+        let width = access_width_bits(flags);
+        let (start_unit, end_unit) = unit_span(offset, length, width);
+
+        let mut combined: u64 = 0;
+        for (i, unit) in (start_unit..end_unit + 1).enumerate() {
+            let unit_value = try!(self.read_region(region, unit * (width / 8), width as u8));
+            combined |= unit_value.wrapping_shl((i * width) as u32);
+        }
+
+        Ok(extract_bits(combined, offset, start_unit, width, length))
+    }
+
+    fn write_region_bits(&mut self, region: &AmlValue, flags: &FieldFlags, offset: usize, length: usize, value: u64) -> Result<(), AmlError> {
+        if length == 0 {
+            return Err(AmlError::AmlValueError);
+        }
+
+        let width = access_width_bits(flags);
+        let update_rule = field_update_rule(flags);
+        let (start_unit, end_unit) = unit_span(offset, length, width);
+
+        let mut consumed = 0;
+        for unit in start_unit..end_unit + 1 {
+            let (lo, hi) = unit_write_window(offset, length, unit, width);
+            let unit_byte_offset = unit * (width / 8);
+
+            let existing = if hi - lo == width {
+                0
+            } else {
+                match update_rule {
+                    FieldUpdateRule::Preserve => try!(self.read_region(region, unit_byte_offset, width as u8)),
+                    FieldUpdateRule::WriteAsOnes => !0u64,
+                    FieldUpdateRule::WriteAsZeros => 0
+                }
+            };
+
+            let new_unit = merge_unit_bits(existing, value, consumed, lo, hi);
+
+            try!(self.write_region(region, unit_byte_offset, width as u8, new_unit));
+            consumed += hi - lo;
+        }
+
+        Ok(())
+    }
+
+    /// Each access unit of an `IndexField` requires its own index-register
+    /// write before the corresponding slice of the data register is
+    /// touched, unlike a `Region`/`Bank` access where one multi-unit pass
+    /// suffices.
+    fn read_indexed_bits(&mut self, index_selector: &str, data_selector: &str, flags: &FieldFlags, offset: usize, length: usize) -> Result<u64, AmlError> {
+        if length == 0 {
+            return Err(AmlError::AmlValueError);
+        }
+
+        let width = access_width_bits(flags);
+        let (start_unit, end_unit) = unit_span(offset, length, width);
+
+        let mut combined: u64 = 0;
+        for (i, unit) in (start_unit..end_unit + 1).enumerate() {
+            try!(self.write_named_integer(index_selector, (unit * (width / 8)) as u64));
+            let data_field = try!(self.named(data_selector));
+            let unit_value = try!(self.read_field(&data_field).and_then(|v| v.get_as_integer()));
+            combined |= unit_value.wrapping_shl((i * width) as u32);
+        }
+
+        Ok(extract_bits(combined, offset, start_unit, width, length))
+    }
+
+    fn write_indexed_bits(&mut self, index_selector: &str, data_selector: &str, flags: &FieldFlags, offset: usize, length: usize, value: u64) -> Result<(), AmlError> {
+        if length == 0 {
+            return Err(AmlError::AmlValueError);
+        }
+
+        let width = access_width_bits(flags);
+        let update_rule = field_update_rule(flags);
+        let (start_unit, end_unit) = unit_span(offset, length, width);
+
+        let mut consumed = 0;
+        for unit in start_unit..end_unit + 1 {
+            let (lo, hi) = unit_write_window(offset, length, unit, width);
+
+            try!(self.write_named_integer(index_selector, (unit * (width / 8)) as u64));
+            let data_field = try!(self.named(data_selector));
+
+            let existing = if hi - lo == width {
+                0
+            } else {
+                match update_rule {
+                    FieldUpdateRule::Preserve => try!(self.read_field(&data_field).and_then(|v| v.get_as_integer())),
+                    FieldUpdateRule::WriteAsOnes => !0u64,
+                    FieldUpdateRule::WriteAsZeros => 0
+                }
+            };
+
+            let new_unit = merge_unit_bits(existing, value, consumed, lo, hi);
+
+            try!(self.write_field(&data_field, &AmlValue::Integer(new_unit)));
+            consumed += hi - lo;
+        }
+
+        Ok(())
+    }
+
+    /// `Acquire`: `thread`, currently executing a method declared
+    /// `Serialized` at `caller_sync_level` (0 if not running inside a
+    /// serialized method), takes ownership of the mutex named by `name`.
+    ///
+    /// Enforces the ACPI sync-level invariant: a serialized method may
+    /// only acquire mutexes whose declared level is >= its own
+    /// `sync_level`, and a thread may never acquire a mutex below the
+    /// highest level it already holds (levels must be acquired in
+    /// non-decreasing order). Re-acquiring a mutex already owned by
+    /// `thread` just bumps its recursion count.
+    ///
+    /// If the mutex is held by another thread, this interpreter has no
+    /// scheduler to block on, so a contended acquire is retried a bounded
+    /// number of times within the call unless `timeout` is `Some(0)`
+    /// ("try once"), which fails immediately. The retries run while
+    /// `&mut self` is held, so nothing can release the mutex between
+    /// them: a contended acquire returns `WaitResult::TimedOut`.
+    pub fn acquire_mutex(&mut self, name: &AmlName, thread: u64, caller_sync_level: u8) -> Result<WaitResult, AmlError> {
+        let value = match self.lookup(name) {
+            Some(v) => v,
+            None => return Err(AmlError::AmlValueError)
+        };
+
+        let (sync_level, timeout) = try!(value.get_as_mutex());
+        let key = name.as_string();
+
+        let attempts = match timeout {
+            Some(0) => 1,
+            _ => MUTEX_ACQUIRE_RETRIES
+        };
+
+        for attempt in 0..attempts {
+            let mut recursive = false;
+            let mut free = false;
+
+            {
+                let state = self.mutexes.entry(key.clone()).or_insert(MutexState { owner: None, recursion: 0 });
+
+                if state.owner == Some(thread) {
+                    state.recursion += 1;
+                    recursive = true;
+                } else if state.owner.is_none() {
+                    free = true;
+                }
+            }
+
+            if recursive {
+                return Ok(WaitResult::Acquired);
+            }
+
+            if !free {
+                if attempt + 1 == attempts {
+                    return Ok(WaitResult::TimedOut);
+                }
+                continue;
+            }
+
+            let current_max = self.thread_sync_levels.get(&thread).and_then(|levels| levels.last().cloned()).unwrap_or(0);
+            let floor = core::cmp::max(current_max, caller_sync_level);
+
+            if sync_level < floor {
+                return Err(AmlError::AmlValueError);
+            }
+
+            {
+                let state = self.mutexes.get_mut(&key).unwrap();
+                state.owner = Some(thread);
+                state.recursion = 1;
+            }
+
+            self.thread_sync_levels.entry(thread).or_insert(Vec::new()).push(sync_level);
+            return Ok(WaitResult::Acquired);
+        }
+
+        Ok(WaitResult::TimedOut)
+    }
+
+    /// `Release`: errors if `name` isn't currently acquired, or is owned
+    /// by a thread other than `thread`.
+    pub fn release_mutex(&mut self, name: &AmlName, thread: u64) -> Result<(), AmlError> {
+        let key = name.as_string();
+
+        let fully_released = {
+            let state = match self.mutexes.get_mut(&key) {
+                Some(s) => s,
+                None => return Err(AmlError::AmlValueError)
+            };
+
+            if state.owner != Some(thread) {
+                return Err(AmlError::AmlValueError);
+            }
+
+            if state.recursion == 0 {
+                return Err(AmlError::AmlValueError);
+            }
+
+            state.recursion -= 1;
+
+            if state.recursion == 0 {
+                state.owner = None;
+                true
+            } else {
+                false
+            }
+        };
+
+        if fully_released {
+            let level = self.lookup(name).and_then(|v| v.get_as_mutex().ok()).map(|m| m.0);
+            if let (Some(levels), Some(level)) = (self.thread_sync_levels.get_mut(&thread), level) {
+                if let Some(pos) = levels.iter().rposition(|&l| l == level) {
+                    levels.remove(pos);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// `Signal`: increments the named `Event`'s counting semaphore.
+    pub fn signal_event(&mut self, name: &AmlName) -> Result<(), AmlError> {
+        if self.lookup(name).and_then(|v| v.get_as_event().ok()).is_none() {
+            return Err(AmlError::AmlValueError);
+        }
+
+        let count = self.events.entry(name.as_string()).or_insert(0);
+        *count += 1;
+        Ok(())
+    }
+
+    /// `Reset`: zeroes the named `Event`'s counting semaphore.
+    pub fn reset_event(&mut self, name: &AmlName) -> Result<(), AmlError> {
+        if self.lookup(name).and_then(|v| v.get_as_event().ok()).is_none() {
+            return Err(AmlError::AmlValueError);
+        }
+
+        self.events.insert(name.as_string(), 0);
+        Ok(())
+    }
+
+    /// `Wait`: consumes one count from the named `Event`'s semaphore if
+    /// available, otherwise reports a timeout. As with `acquire_mutex`,
+    /// there's no scheduler to block a thread on, so anything but
+    /// `timeout == Some(0)` ("try once") is retried a bounded number of
+    /// times within the call before giving up.
+    pub fn wait_event(&mut self, name: &AmlName, timeout: Option<u64>) -> Result<WaitResult, AmlError> {
+        if self.lookup(name).and_then(|v| v.get_as_event().ok()).is_none() {
+            return Err(AmlError::AmlValueError);
+        }
+
+        let key = name.as_string();
+
+        let attempts = match timeout {
+            Some(0) => 1,
+            _ => MUTEX_ACQUIRE_RETRIES
+        };
+
+        for attempt in 0..attempts {
+            let count = self.events.entry(key.clone()).or_insert(0);
+
+            if *count > 0 {
+                *count -= 1;
+                return Ok(WaitResult::Acquired);
+            }
+
+            if attempt + 1 == attempts {
+                return Ok(WaitResult::TimedOut);
+            }
+        }
+
+        Ok(WaitResult::TimedOut)
+    }
+}
+
+/// Bit width of one aligned access to a field's backing region/register,
+/// per the field's declared `AccessType` (defaulting to byte-wide for
+/// `AnyAcc`).
+fn access_width_bits(flags: &FieldFlags) -> usize {
+    let width = flags.access_width();
+    if width == 0 { 8 } else { width }
+}
+
+fn field_update_rule(flags: &FieldFlags) -> FieldUpdateRule {
+    flags.update_rule()
+}
+
+fn bit_mask(length: usize) -> u64 {
+    if length >= 64 {
+        !0u64
+    } else {
+        (1u64 << length) - 1
+    }
+}
+
+/// The inclusive `(start_unit, end_unit)` range of `width`-sized, aligned
+/// access units a `offset..offset+length` bit field spans.
+fn unit_span(offset: usize, length: usize, width: usize) -> (usize, usize) {
+    (offset / width, (offset + length - 1) / width)
+}
+
+/// Pull the `offset..offset+length` bit slice out of `combined`, the
+/// little-endian concatenation of the units from `start_unit` onward.
+fn extract_bits(combined: u64, offset: usize, start_unit: usize, width: usize, length: usize) -> u64 {
+    let shift = offset - start_unit * width;
+    (combined >> shift) & bit_mask(length)
+}
+
+/// The `(lo, hi)` bit range within a single `width`-sized `unit` that a
+/// `offset..offset+length` bit field overlaps.
+fn unit_write_window(offset: usize, length: usize, unit: usize, width: usize) -> (usize, usize) {
+    let unit_bit_start = unit * width;
+    let lo = if offset > unit_bit_start { offset - unit_bit_start } else { 0 };
+    let hi = core::cmp::min(offset + length - unit_bit_start, width);
+    (lo, hi)
+}
+
+/// Fold the next `hi - lo` bits of `value` (after `consumed` bits have
+/// already been written to prior units) into the `[lo, hi)` slice of
+/// `existing`, leaving the rest of `existing` untouched.
+fn merge_unit_bits(existing: u64, value: u64, consumed: usize, lo: usize, hi: usize) -> u64 {
+    let mask = bit_mask(hi - lo) << lo;
+    let unit_value = ((value >> consumed) << lo) & mask;
+    (existing & !mask) | unit_value
+}
+
+/// Split a `offset..offset+length` bit field into the ≤64-bit,
+/// little-endian chunks `read_field_buffer`/`write_field_buffer` gather
+/// or scatter one at a time, since a single `u64` can't hold more than
+/// 64 bits of it. Each entry is `(chunk_offset, chunk_bits)`.
+fn chunk_ranges(offset: usize, length: usize) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut bit_offset = offset;
+    let mut remaining = length;
+
+    while remaining > 0 {
+        let chunk_bits = core::cmp::min(remaining, 64);
+        ranges.push((bit_offset, chunk_bits));
+        bit_offset += chunk_bits;
+        remaining -= chunk_bits;
+    }
+
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_span_aligned() {
+        assert_eq!(unit_span(0, 8, 8), (0, 0));
+        assert_eq!(unit_span(8, 8, 8), (1, 1));
+    }
+
+    #[test]
+    fn unit_span_misaligned_crosses_unit_boundary() {
+        // A 12-bit field starting at bit 4, with byte-wide (8-bit) access
+        // units, spans unit 0 (bits 0..8) and unit 1 (bits 8..16).
+        assert_eq!(unit_span(4, 12, 8), (0, 1));
+    }
+
+    #[test]
+    fn extract_bits_reads_misaligned_field_out_of_combined_units() {
+        // Two little-endian bytes 0xAB, 0xCD combine to 0xCDAB. The
+        // 12-bit field at bit offset 4 covers bits 4..16, i.e. 0xCDA.
+        let combined = 0xCDABu64;
+        assert_eq!(extract_bits(combined, 4, 0, 8, 12), 0xCDA);
+    }
+
+    #[test]
+    fn write_then_read_misaligned_12_bit_field_across_byte_boundary() {
+        // A 2-byte register file accessed in 8-bit units.
+        let mut mem = [0u8; 2];
+        let width = 8;
+        let offset = 4;
+        let length = 12;
+        let value: u64 = 0xABC;
+
+        let (start_unit, end_unit) = unit_span(offset, length, width);
+        let mut consumed = 0;
+        for unit in start_unit..end_unit + 1 {
+            let (lo, hi) = unit_write_window(offset, length, unit, width);
+            let existing = mem[unit] as u64;
+            mem[unit] = merge_unit_bits(existing, value, consumed, lo, hi) as u8;
+            consumed += hi - lo;
+        }
+
+        let mut combined: u64 = 0;
+        for (i, unit) in (start_unit..end_unit + 1).enumerate() {
+            combined |= (mem[unit] as u64).wrapping_shl((i * width) as u32);
+        }
+
+        assert_eq!(extract_bits(combined, offset, start_unit, width, length), value);
+    }
+
+    #[test]
+    fn merge_unit_bits_preserves_bits_outside_the_write_window() {
+        // Writing 0b101 into bits [2, 5) of a unit whose other bits are
+        // all set must leave those other bits untouched.
+        let written = merge_unit_bits(0xFF, 0b101, 0, 2, 5);
+        assert_eq!(written & 0xFF, 0xF7);
+    }
+
+    #[test]
+    fn merge_unit_bits_honors_write_as_zeros_fill() {
+        // Same partial write, but with a zero-filled `existing` (as
+        // `FieldUpdateRule::WriteAsZeros` would pass in) only sets the
+        // bits the write actually touches.
+        let written = merge_unit_bits(0, 0b101, 0, 2, 5);
+        assert_eq!(written & 0xFF, 0b0001_0100);
+    }
+
+    #[test]
+    fn chunk_ranges_splits_wide_fields_into_64_bit_pieces() {
+        assert_eq!(chunk_ranges(0, 64), vec![(0, 64)]);
+        assert_eq!(chunk_ranges(0, 70), vec![(0, 64), (64, 6)]);
+        assert_eq!(chunk_ranges(0, 128), vec![(0, 64), (64, 64)]);
+    }
+
+    #[test]
+    fn round_trip_128_bit_field_through_write_then_read_chunking() {
+        // A 128-bit register file as four 32-bit-wide access units.
+        let width = 32;
+        let offset = 0;
+        let length = 128;
+        let value_lo: u64 = 0x1111_1111_2222_2222;
+        let value_hi: u64 = 0x3333_3333_4444_4444;
+
+        let mut mem = [0u32; 4];
+
+        for (chunk_offset, chunk_bits) in chunk_ranges(offset, length) {
+            let chunk_value = if chunk_offset == 0 { value_lo } else { value_hi };
+            let (start_unit, end_unit) = unit_span(chunk_offset, chunk_bits, width);
+
+            let mut consumed = 0;
+            for unit in start_unit..end_unit + 1 {
+                let (lo, hi) = unit_write_window(chunk_offset, chunk_bits, unit, width);
+                let existing = mem[unit] as u64;
+                mem[unit] = merge_unit_bits(existing, chunk_value, consumed, lo, hi) as u32;
+                consumed += hi - lo;
+            }
+        }
+
+        let mut read_lo = 0u64;
+        let mut read_hi = 0u64;
+        for (chunk_offset, chunk_bits) in chunk_ranges(offset, length) {
+            let (start_unit, end_unit) = unit_span(chunk_offset, chunk_bits, width);
+
+            let mut combined = 0u64;
+            for (i, unit) in (start_unit..end_unit + 1).enumerate() {
+                combined |= (mem[unit] as u64).wrapping_shl((i * width) as u32);
+            }
+
+            let value = extract_bits(combined, chunk_offset, start_unit, width, chunk_bits);
+            if chunk_offset == 0 { read_lo = value } else { read_hi = value }
+        }
+
+        assert_eq!(read_lo, value_lo);
+        assert_eq!(read_hi, value_hi);
     }
-    
-    namespace + &modifier
 }